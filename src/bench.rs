@@ -0,0 +1,78 @@
+use rayon::prelude::*;
+use tokio::runtime::Handle;
+
+use crate::dictionary;
+use crate::error::GameError;
+use crate::solver;
+
+/// Aggregate statistics from running the solver against many dictionary words.
+pub(crate) struct BenchReport {
+    pub(crate) total_words: usize,
+    pub(crate) wins: usize,
+    pub(crate) avg_wrong_guesses_on_wins: f64,
+    pub(crate) steps_left_distribution: Vec<(i32, usize)>,
+    pub(crate) hardest_words: Vec<String>,
+}
+
+/// Runs the solver against every word in the dictionary (optionally capped at
+/// `max_words`) in parallel via rayon, and summarizes the outcomes.
+///
+/// `handle` lets each rayon worker thread drive the solver's async calls
+/// synchronously without spinning up its own tokio runtime.
+pub(crate) fn run(handle: Handle, max_words: Option<usize>, max_steps: i32) -> Result<BenchReport, GameError> {
+    let entries = handle.block_on(dictionary::load_dictionary())?;
+    let mut words: Vec<String> = entries.iter().map(|(word, _)| word.clone()).collect();
+    if let Some(limit) = max_words {
+        words.truncate(limit);
+    }
+
+    let results: Vec<(String, solver::SolverOutcome)> = words
+        .par_iter()
+        .map(|word| {
+            handle
+                .block_on(solver::solve_with_entries(&entries, word, max_steps, false))
+                .map(|outcome| (word.clone(), outcome))
+        })
+        .collect::<Result<Vec<_>, GameError>>()?;
+
+    let total_words = results.len();
+    let wins: Vec<&solver::SolverOutcome> = results.iter().filter(|(_, outcome)| outcome.won).map(|(_, outcome)| outcome).collect();
+    let win_count = wins.len();
+
+    let avg_wrong_guesses_on_wins = if win_count > 0 {
+        wins.iter().map(|outcome| outcome.wrong_guesses as f64).sum::<f64>() / win_count as f64
+    } else {
+        0.0
+    };
+
+    let mut steps_left_distribution: Vec<(i32, usize)> = Vec::new();
+    for (_, outcome) in results.iter().filter(|(_, outcome)| outcome.won) {
+        match steps_left_distribution.iter_mut().find(|(steps, _)| *steps == outcome.steps_left) {
+            Some((_, count)) => *count += 1,
+            None => steps_left_distribution.push((outcome.steps_left, 1)),
+        }
+    }
+    steps_left_distribution.sort_by_key(|(steps, _)| *steps);
+
+    let mut hardest_words: Vec<String> = results.iter().filter(|(_, outcome)| !outcome.won).map(|(word, _)| word.clone()).collect();
+    hardest_words.sort();
+
+    Ok(BenchReport { total_words, wins: win_count, avg_wrong_guesses_on_wins, steps_left_distribution, hardest_words })
+}
+
+/// Prints a human-readable summary of a [`BenchReport`] to stdout.
+pub(crate) fn print_report(report: &BenchReport) {
+    let win_rate = if report.total_words > 0 { report.wins as f64 / report.total_words as f64 * 100.0 } else { 0.0 };
+    println!("Benchmarked {} words", report.total_words);
+    println!("Win rate: {:.1}% ({}/{})", win_rate, report.wins, report.total_words);
+    println!("Average wrong guesses on wins: {:.2}", report.avg_wrong_guesses_on_wins);
+
+    println!("Steps-left distribution on wins:");
+    for (steps, count) in &report.steps_left_distribution {
+        println!("  {} steps left: {}", steps, count);
+    }
+
+    if !report.hardest_words.is_empty() {
+        println!("Hardest words (losses): {}", report.hardest_words.join(", "));
+    }
+}
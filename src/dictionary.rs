@@ -0,0 +1,99 @@
+#[cfg(not(feature = "builtin_wlist"))]
+use std::fs::File;
+#[cfg(not(feature = "builtin_wlist"))]
+use std::io::{self, BufRead};
+
+use clap::ValueEnum;
+
+use crate::error::GameError;
+
+/// Difficulty tier for a dictionary word, used to let players pick a theme
+/// instead of facing the whole word list at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// Parses a tag as stored in the dictionary file (`word,<tag>`).
+    fn parse_tag(tag: &str) -> Option<Difficulty> {
+        match tag.trim().to_lowercase().as_str() {
+            "easy" => Some(Difficulty::Easy),
+            "medium" => Some(Difficulty::Medium),
+            "hard" => Some(Difficulty::Hard),
+            _ => None,
+        }
+    }
+
+    /// Falls back to a length-based estimate for untagged words, since length
+    /// is a reasonable proxy when no curated tag is available.
+    fn estimate_from_length(word: &str) -> Difficulty {
+        match word.chars().count() {
+            0..=5 => Difficulty::Easy,
+            6..=8 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+}
+
+/// Parses one dictionary line, which is either a bare word or a
+/// `word,difficulty` pair. Curated tags let familiar-but-long words (e.g.
+/// "waterfall") land in an easier tier than their length alone would suggest.
+fn parse_entry(line: &str) -> (String, Difficulty) {
+    match line.split_once(',') {
+        Some((word, tag)) => {
+            let word = word.trim().to_string();
+            let difficulty = Difficulty::parse_tag(tag).unwrap_or_else(|| Difficulty::estimate_from_length(&word));
+            (word, difficulty)
+        }
+        None => {
+            let word = line.trim().to_string();
+            let difficulty = Difficulty::estimate_from_length(&word);
+            (word, difficulty)
+        }
+    }
+}
+
+#[cfg(feature = "builtin_wlist")]
+const BUILTIN_WORDLIST: &str = include_str!("../dictionary.txt");
+
+/// Loads the dictionary and tags each word with a [`Difficulty`] tier.
+///
+/// With the `builtin_wlist` feature enabled the word list is embedded into
+/// the binary at compile time, so the game works no matter which directory
+/// it's launched from. Otherwise it falls back to reading `dictionary.txt`
+/// from the current directory, matching the previous behaviour.
+pub async fn load_dictionary() -> Result<Vec<(String, Difficulty)>, GameError> {
+    let entries: Vec<(String, Difficulty)>;
+
+    #[cfg(feature = "builtin_wlist")]
+    {
+        entries = BUILTIN_WORDLIST.lines().filter(|line| !line.is_empty()).map(parse_entry).collect();
+    }
+
+    #[cfg(not(feature = "builtin_wlist"))]
+    {
+        let file = File::open("dictionary.txt").map_err(GameError::DictionaryNotFound)?;
+        entries = io::BufReader::new(file)
+            .lines()
+            .map(|line| line.map(|line| parse_entry(&line)))
+            .collect::<io::Result<Vec<_>>>()?;
+    }
+
+    if entries.is_empty() {
+        return Err(GameError::EmptyDictionary);
+    }
+
+    Ok(entries)
+}
+
+/// Narrows a tagged dictionary down to the words matching a single tier.
+pub fn filter_by_difficulty(entries: &[(String, Difficulty)], difficulty: Difficulty) -> Vec<String> {
+    entries
+        .iter()
+        .filter(|(_, word_difficulty)| *word_difficulty == difficulty)
+        .map(|(word, _)| word.clone())
+        .collect()
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+/// Everything that can go wrong while setting up or running a game of hangman.
+#[derive(Debug, Error)]
+pub(crate) enum GameError {
+    #[cfg(not(feature = "builtin_wlist"))]
+    #[error("could not open the dictionary file: {0}")]
+    DictionaryNotFound(#[source] std::io::Error),
+
+    #[error("the dictionary contains no words")]
+    EmptyDictionary,
+
+    #[error("the frames file contains no frames")]
+    EmptyFrames,
+
+    #[error("'{0}' is not a valid word")]
+    InvalidWord(String),
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
@@ -0,0 +1,278 @@
+use std::io::{self, Write};
+
+use async_recursion::async_recursion;
+use colored::Colorize;
+use rand::seq::SliceRandom;
+
+use crate::dictionary::{self, Difficulty};
+use crate::error::GameError;
+
+pub(crate) struct GameManager {
+    word: Vec<Letter>,
+    correct_guesses: Vec<char>,
+    incorrect_guesses: Vec<char>,
+    steps_left: i32,
+    max_steps: i32,
+    min_word_length: i32,
+    max_word_length: i32,
+    difficulty: Option<Difficulty>,
+}
+
+impl GameManager {
+    pub(crate) fn new(max_steps: i32, min_word_length: i32, max_word_length: i32, difficulty: Option<Difficulty>) -> GameManager {
+        GameManager {
+            word: Vec::new(),
+            correct_guesses: Vec::new(),
+            incorrect_guesses: Vec::new(),
+            steps_left: max_steps,
+            max_steps,
+            min_word_length,
+            max_word_length,
+            difficulty,
+        }
+    }
+
+    pub(crate) async fn start_game(&mut self) -> Result<(), GameError> {
+        loop {
+            self.flush();
+            let word = self.choose_word().await?;
+            self.init_word(&word).await;
+
+            self.play().await?;
+
+            let play_again = self.ask("Do you want to play again? (Y/N) ").await?;
+            if play_again.to_lowercase() != "y" {
+                println!("Goodbye!");
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_recursion]
+    async fn choose_word(&self) -> Result<String, GameError> {
+        let dictionary_or_not = self.ask("Do you want to use the default dictionary? (Y/N) ").await?;
+
+        match dictionary_or_not.to_lowercase().as_str() {
+            "y" | "" => {
+                let words = self.load_default_dictionary().await?;
+                if words.is_empty() {
+                    return Err(GameError::EmptyDictionary);
+                }
+                Ok(words.choose(&mut rand::thread_rng()).unwrap().clone())
+            }
+            "n" => {
+                loop {
+                    let word = self.ask("Enter a word: ").await?;
+                    let mut is_valid = true;
+
+                    for i in word.chars() {
+                        if !i.is_alphabetic() || i.to_string().len() != 1 {
+                            is_valid = false;
+                            break
+                        }
+                    }
+
+                    if word.len() < (self.min_word_length as usize) || word.len() > (self.max_word_length as usize) {
+                        println!("Word must be between {} and {} characters long", self.min_word_length, self.max_word_length);
+                    } else if !is_valid {
+                        println!("Word must not contain special chars or symbols.")
+                    } else {
+                        break Ok(word);
+                    }
+                }
+            }
+            _ => {
+                println!("Please enter y or n");
+                self.choose_word().await
+            }
+        }
+    }
+
+    async fn load_default_dictionary(&self) -> Result<Vec<String>, GameError> {
+        let entries = dictionary::load_dictionary().await?;
+        let words: Vec<String> = match self.difficulty {
+            Some(difficulty) => dictionary::filter_by_difficulty(&entries, difficulty),
+            None => entries.into_iter().map(|(word, _)| word).collect(),
+        };
+
+        Ok(words
+            .into_iter()
+            .filter(|word| word.len() >= (self.min_word_length as usize) && word.len() <= (self.max_word_length as usize))
+            .collect())
+    }
+
+    pub(crate) async fn init_word(&mut self, word: &str) {
+        self.word.clear();
+        for letter in word.chars() {
+            self.word.push(Letter { letter, status: letter == ' ' });
+        }
+    }
+
+    async fn print_word(&self, end: bool) -> String {
+        if end {
+            self.word.iter().map(|letter| letter.letter).collect()
+        } else {
+            self.word
+                .iter()
+                .map(|letter| if letter.status { letter.letter.to_string().green().to_string() } else { "_".to_string() })
+                .collect()
+        }
+    }
+
+    async fn print_missed_letters(&self) -> String {
+        self.incorrect_guesses.iter().map(|letter| letter.to_string().red().to_string()).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Marks every occurrence of `letter` in the word as revealed, recording the
+    /// guess as a hit. Returns whether the letter actually appears in the word.
+    pub(crate) async fn check_letter(&mut self, letter: char) -> bool {
+        let mut found = false;
+        for i in 0..self.word.len() {
+            if self.word[i].letter.to_lowercase().next().unwrap() == letter.to_ascii_lowercase() {
+                self.word[i].status = true;
+                found = true;
+                self.correct_guesses.push(letter.to_lowercase().next().unwrap());
+            }
+        }
+        found
+    }
+
+    /// Records a wrong guess and spends one of the remaining steps.
+    pub(crate) fn record_miss(&mut self, letter: char) {
+        self.incorrect_guesses.push(letter);
+        self.steps_left -= 1;
+    }
+
+    pub(crate) async fn check_win(&self) -> bool {
+        self.word.iter().all(|letter| letter.status)
+    }
+
+    pub(crate) async fn check_lose(&self) -> bool {
+        self.steps_left == 0
+    }
+
+    pub(crate) fn steps_left(&self) -> i32 {
+        self.steps_left
+    }
+
+    /// The currently revealed pattern: `Some(letter)` for positions that have
+    /// been guessed, `None` for positions still hidden.
+    pub(crate) fn revealed_pattern(&self) -> Vec<Option<char>> {
+        self.word
+            .iter()
+            .map(|letter| if letter.status { Some(letter.letter.to_ascii_lowercase()) } else { None })
+            .collect()
+    }
+
+    /// Letters guessed so far that are confirmed absent from the word.
+    pub(crate) fn incorrect_letters(&self) -> &[char] {
+        &self.incorrect_guesses
+    }
+
+    async fn print_status(&self, end: bool) -> Result<(), GameError> {
+        let frame = generate_frame(self.steps_left as usize, self.print_word(end).await, self.word.len()).await?;
+        println!("{}", frame);
+        if !self.incorrect_guesses.is_empty() {
+            println!("Missed letters: {}", self.print_missed_letters().await);
+        }
+        Ok(())
+    }
+
+    async fn play(&mut self) -> Result<(), GameError> {
+        loop {
+            self.print_status(false).await?;
+            let answer = self.ask("Enter a letter: ").await?;
+            let Some(letter) = answer.chars().next() else {
+                println!("Please enter a letter.");
+                continue;
+            };
+            if letter.is_alphabetic() && letter.to_string().len() == 1 {
+                let letter = letter.to_lowercase().next().unwrap();
+                if self.correct_guesses.contains(&letter) || self.incorrect_guesses.contains(&letter) {
+                    println!("You already guessed this letter");
+                } else {
+                    if !self.check_letter(letter).await {
+                        self.record_miss(letter);
+                    }
+                    if self.check_win().await {
+                        self.print_status(true).await?;
+                        println!("You won!");
+                        break;
+                    }
+                    if self.check_lose().await {
+                        self.print_status(true).await?;
+                        println!("You lost!");
+                        break;
+                    }
+                }
+            } else {
+                println!("Please enter only one letter.");
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        self.steps_left = self.max_steps;
+        self.word.clear();
+        self.correct_guesses.clear();
+        self.incorrect_guesses.clear();
+    }
+
+    async fn ask(&self, question: &str) -> Result<String, GameError> {
+        print!("{}", question);
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        Ok(answer.trim().to_string())
+    }
+}
+
+struct Letter {
+    letter: char,
+    status: bool,
+}
+
+/// Embedded at compile time so the hangman frames render no matter which
+/// directory the binary is launched from, same rationale as the dictionary.
+const BUILTIN_FRAMES: &str = include_str!("../frames.txt");
+
+async fn generate_frame(steps: usize, word: String, visible_len: usize) -> Result<String, GameError> {
+    let frames = parse_frames(BUILTIN_FRAMES);
+
+    if frames.is_empty() {
+        return Err(GameError::EmptyFrames);
+    }
+
+    let header = "########## Hangman ##########";
+    let footer = format!("########## {} steps ##########", steps);
+    let frame_width = visible_len.max(25);
+    let padding = frame_width - visible_len;
+    let word_frame = format!("# {}{}{} #", " ".repeat(padding / 2), word, " ".repeat(padding / 2 + padding % 2));
+    let frame_index = 7_usize.saturating_sub(steps);
+
+    Ok(format!("{}\n{}\n{}\n{}", header, frames[frame_index].join("\n"), word_frame, footer))
+}
+
+fn parse_frames(raw: &str) -> Vec<Vec<String>> {
+    let mut frames = Vec::new();
+    let mut current_frame = Vec::new();
+
+    for line in raw.lines() {
+        if line.starts_with('-') {
+            if !current_frame.is_empty() {
+                frames.push(current_frame.clone());
+                current_frame.clear();
+            }
+        } else {
+            current_frame.push(line.to_string());
+        }
+    }
+
+    if !current_frame.is_empty() {
+        frames.push(current_frame);
+    }
+
+    frames
+}
@@ -1,229 +1,126 @@
-use std::fs::File;
-use std::io::{self, BufRead, Write};
-use std::path::Path;
-use async_recursion::async_recursion;
-use rand::seq::SliceRandom;
+use clap::Parser;
+
+mod bench;
+mod dictionary;
+mod error;
+mod game;
+mod solver;
+
+use dictionary::Difficulty;
+use error::GameError;
+use game::GameManager;
 
 const MIN_WORD_LENGTH: i32 = 3;
 const MAX_WORD_LENGTH: i32 = 25;
 const STEPS: i32 = 7;
 
-struct GameManager {
-    word: Vec<Letter>,
-    already_guessed: Vec<char>,
-    steps_left: i32,
+/// Command-line difficulty knobs for a game of hangman.
+#[derive(Parser, Debug, Clone)]
+#[command(author, version, about = "A terminal hangman game")]
+struct Cli {
+    /// Number of wrong guesses allowed before losing
+    #[arg(long, default_value_t = STEPS, value_parser = clap::value_parser!(i32).range(1..))]
+    max_steps: i32,
+
+    /// Shortest word the game/dictionary will accept
+    #[arg(long, default_value_t = MIN_WORD_LENGTH, value_parser = clap::value_parser!(i32).range(1..))]
+    min_length: i32,
+
+    /// Longest word the game/dictionary will accept
+    #[arg(long, default_value_t = MAX_WORD_LENGTH, value_parser = clap::value_parser!(i32).range(1..))]
+    max_length: i32,
+
+    /// Restrict the default dictionary to a single difficulty tier
+    #[arg(long)]
+    difficulty: Option<Difficulty>,
+
+    /// Play out a word automatically instead of prompting for guesses, solving for the given word
+    #[arg(long, value_name = "WORD")]
+    solve: Option<String>,
+
+    /// Benchmark the solver's win rate across the whole dictionary instead of playing
+    #[arg(long)]
+    bench: bool,
+
+    /// Cap the number of dictionary words sampled by --bench
+    #[arg(long, value_name = "N")]
+    bench_words: Option<usize>,
 }
 
-impl GameManager {
-    fn new() -> GameManager {
-        GameManager {
-            word: Vec::new(),
-            already_guessed: Vec::new(),
-            steps_left: STEPS,
-        }
-    }
-
-    async fn start_game(&mut self) {
-        loop {
-            self.flush();
-            let word = self.choose_word().await;
-            self.init_word(&word).await;
-
-            self.play().await;
-
-            let mut play_again = String::new();
-            print!("Do you want to play again? (Y/N) ");
-            io::stdout().flush().unwrap();
-            io::stdin().read_line(&mut play_again).unwrap();
-            if play_again.trim().to_lowercase() != "y" {
-                println!("Goodbye!");
-                break;
-            }
-        }
-    }
-
-    #[async_recursion]
-    async fn choose_word(&self) -> String {
-        print!("Do you want to use the default dictionary? (Y/N) ");
-        io::stdout().flush().unwrap();
-
-        let mut dictionary_or_not = String::new();
-        io::stdin().read_line(&mut dictionary_or_not).unwrap();
-
-        match dictionary_or_not.trim().to_lowercase().as_str() {
-            "y" | "" => self.load_default_dictionary().await.choose(&mut rand::thread_rng()).unwrap().clone(),
-            "n" => {
-                loop {
-                    print!("Enter a word: ");
-                    io::stdout().flush().unwrap();
-                    let mut word = String::new();
-                    io::stdin().read_line(&mut word).unwrap();
-                    let word = word.trim();
-                    let mut is_valid = true;
-
-                    for i in word.chars() {
-                        if !i.is_alphabetic() || i.to_string().len() != 1 {
-                            is_valid = false;
-                            break
-                        }
-                    }
-
-                    if word.len() < (MIN_WORD_LENGTH as usize) || word.len() > (MAX_WORD_LENGTH as usize) {
-                        println!("Word must be between {} and {} characters long", MIN_WORD_LENGTH, MAX_WORD_LENGTH);
-                    } else if !is_valid {
-                        println!("Word must not contain special chars or symbols.")
-                    } else {
-                        break String::from(word);
-                    }
-                }
-            }
-            _ => {
-                println!("Please enter y or n");
-                self.choose_word().await
-            }
+impl Cli {
+    /// Validates that the length bounds make sense together.
+    fn validate(&self) -> Result<(), String> {
+        if self.min_length > self.max_length {
+            return Err(format!(
+                "--min-length ({}) cannot be greater than --max-length ({})",
+                self.min_length, self.max_length
+            ));
         }
+        Ok(())
     }
+}
 
-    async fn load_default_dictionary(&self) -> Vec<String> {
-        let file = File::open("dictionary.txt").unwrap();
-        io::BufReader::new(file).lines().map(|line| line.unwrap()).collect()
+#[tokio::main]
+async fn main() {
+    let cli = Cli::parse();
+    if let Err(err) = cli.validate() {
+        eprintln!("{}", err);
+        std::process::exit(1);
     }
 
-    async fn init_word(&mut self, word: &str) {
-        self.word.clear();
-        for letter in word.chars() {
-            self.word.push(Letter { letter, status: letter == ' ' });
-        }
+    let result = run(cli).await;
+    if let Err(err) = result {
+        eprintln!("Error: {}", err);
+        std::process::exit(1);
     }
+}
 
-    async fn print_word(&self, end: bool) -> String {
-        if end {
-            self.word.iter().map(|letter| letter.letter).collect()
+async fn run(cli: Cli) -> Result<(), GameError> {
+    if cli.bench {
+        let handle = tokio::runtime::Handle::current();
+        let max_steps = cli.max_steps;
+        let max_words = cli.bench_words;
+        let report = tokio::task::spawn_blocking(move || bench::run(handle, max_words, max_steps)).await.expect("bench task panicked")?;
+        bench::print_report(&report);
+        return Ok(());
+    }
+
+    if let Some(word) = &cli.solve {
+        let outcome = solver::solve(word, cli.max_steps, true).await?;
+        if outcome.won {
+            println!("Solver won with {} wrong guesses ({} steps left).", outcome.wrong_guesses, outcome.steps_left);
+        } else if outcome.candidates_exhausted {
+            println!("Solver gave up: no dictionary word matches '{}' anymore (is it in the dictionary?).", word);
         } else {
-            self.word.iter().map(|letter| if letter.status { letter.letter } else { '_' }).collect()
-        }
-    }
-
-    async fn check_letter(&mut self, letter: char) -> bool {
-        let mut found = false;
-        for i in 0..self.word.len() {
-            if self.word[i].letter.to_lowercase().next().unwrap() == letter.to_ascii_lowercase() {
-                self.word[i].status = true;
-                found = true;
-                self.already_guessed.push(letter.to_lowercase().next().unwrap());
-            }
+            println!("Solver lost after {} wrong guesses.", outcome.wrong_guesses);
         }
-        found
+        return Ok(());
     }
 
-    async fn check_win(&self) -> bool {
-        self.word.iter().all(|letter| letter.status)
-    }
-
-    async fn check_lose(&self) -> bool {
-        self.steps_left == 0
-    }
-
-    async fn print_status(&self, end: bool) {
-        println!("{}", generate_frame(self.steps_left as usize, self.print_word(end).await).await);
-    }
-
-    async fn play(&mut self) {
-        loop {
-            self.print_status(false).await;
-            let letter = self.ask("Enter a letter: ").await.chars().next().unwrap();
-            if letter.is_alphabetic() && letter.to_string().len() == 1 {
-                if self.already_guessed.contains(&letter.to_lowercase().next().unwrap()) {
-                    println!("You already guessed this letter");
-                } else {
-                    if !self.check_letter(letter).await {
-                        self.already_guessed.push(letter.to_lowercase().next().unwrap());
-                        self.steps_left -= 1;
-                    }
-                    if self.check_win().await {
-                        self.print_status(true).await;
-                        println!("You won!");
-                        break;
-                    }
-                    if self.check_lose().await {
-                        self.print_status(true).await;
-                        println!("You lost!");
-                        break;
-                    }
-                }
-            } else {
-                println!("Please enter only one letter.");
-            }
-        }
-    }
-
-    fn flush(&mut self) {
-        self.steps_left = STEPS;
-        self.word.clear();
-        self.already_guessed.clear();
-    }
-
-    async fn ask(&self, question: &str) -> String {
-        print!("{}", question);
-        io::stdout().flush().unwrap();
-        let mut answer = String::new();
-        io::stdin().read_line(&mut answer).unwrap();
-        answer.trim().to_string()
-    }
+    let mut game_manager = GameManager::new(cli.max_steps, cli.min_length, cli.max_length, cli.difficulty);
+    game_manager.start_game().await
 }
 
-struct Letter {
-    letter: char,
-    status: bool,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-async fn generate_frame(steps: usize, word: String) -> String {
-    let frames = read_frames_from_file("frames.txt").await.unwrap_or_else(|_| {
-        println!("Failed to read frames from file.");
-        std::process::exit(1);
-    });
-
-    if frames.is_empty() {
-        println!("No frames available.");
-        std::process::exit(1)
+    fn cli_with_bounds(min_length: i32, max_length: i32) -> Cli {
+        Cli { max_steps: STEPS, min_length, max_length, difficulty: None, solve: None, bench: false, bench_words: None }
     }
 
-    let header = "########## Hangman ##########";
-    let footer = format!("########## {} steps ##########", steps);
-    let word_frame = format!("# {}{}{} #", " ".repeat((25 - word.len()) / 2), word, " ".repeat((25 - word.len()) / 2 + (25 - word.len()) % 2));
-    let frame_index = if steps > 7 { 0 } else { 7 - steps };
-
-    format!("{}\n{}\n{}\n{}", header, frames[frame_index].join("\n"), word_frame, footer)
-}
-
-async fn read_frames_from_file<P: AsRef<Path>>(file_path: P) -> io::Result<Vec<Vec<String>>> {
-    let file = File::open(file_path)?;
-    let lines = io::BufReader::new(file).lines();
-
-    let mut frames = Vec::new();
-    let mut current_frame = Vec::new();
-
-    for line in lines {
-        let line = line?;
-        if line.starts_with('-') {
-            if !current_frame.is_empty() {
-                frames.push(current_frame.clone());
-                current_frame.clear();
-            }
-        } else {
-            current_frame.push(line);
-        }
+    #[test]
+    fn validate_rejects_min_length_greater_than_max_length() {
+        assert!(cli_with_bounds(10, 5).validate().is_err());
     }
 
-    if !current_frame.is_empty() {
-        frames.push(current_frame);
+    #[test]
+    fn validate_accepts_equal_bounds() {
+        assert!(cli_with_bounds(5, 5).validate().is_ok());
     }
 
-    Ok(frames)
-}
-
-#[tokio::main]
-async fn main() {
-    let mut game_manager = GameManager::new();
-    game_manager.start_game().await;
+    #[test]
+    fn validate_accepts_min_length_less_than_max_length() {
+        assert!(cli_with_bounds(3, 25).validate().is_ok());
+    }
 }
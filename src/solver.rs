@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::dictionary::{self, Difficulty};
+use crate::error::GameError;
+use crate::game::GameManager;
+
+/// Letters in descending order of overall English letter frequency, used to
+/// break ties when several untried letters touch the same number of candidates.
+const ENGLISH_LETTER_FREQUENCY: [char; 26] = [
+    'e', 't', 'a', 'o', 'i', 'n', 's', 'h', 'r', 'd', 'l', 'c', 'u', 'm', 'w', 'f', 'g', 'y', 'p', 'b', 'v', 'k', 'j', 'x', 'q', 'z',
+];
+
+/// Outcome of a single solver run against one target word.
+pub(crate) struct SolverOutcome {
+    pub(crate) won: bool,
+    pub(crate) wrong_guesses: i32,
+    pub(crate) steps_left: i32,
+    /// Set when the solver gave up because no dictionary word matched the
+    /// revealed pattern anymore, rather than running out of guesses — most
+    /// often a sign `target_word` isn't in the dictionary.
+    pub(crate) candidates_exhausted: bool,
+}
+
+/// Plays the guesser role automatically against `target_word`, loading the
+/// dictionary itself. Prefer [`solve_with_entries`] when solving many words
+/// in a row (e.g. benchmarking) so the dictionary is only loaded once.
+pub(crate) async fn solve(target_word: &str, max_steps: i32, verbose: bool) -> Result<SolverOutcome, GameError> {
+    let entries = dictionary::load_dictionary().await?;
+    solve_with_entries(&entries, target_word, max_steps, verbose).await
+}
+
+/// Plays the guesser role automatically against `target_word`, narrowing a
+/// candidate set drawn from an already-loaded `entries` dictionary after
+/// every guess.
+pub(crate) async fn solve_with_entries(
+    entries: &[(String, Difficulty)],
+    target_word: &str,
+    max_steps: i32,
+    verbose: bool,
+) -> Result<SolverOutcome, GameError> {
+    if target_word.is_empty() || !target_word.chars().all(|letter| letter.is_alphabetic()) {
+        return Err(GameError::InvalidWord(target_word.to_string()));
+    }
+
+    let mut candidates: Vec<String> = entries
+        .iter()
+        .map(|(word, _)| word.clone())
+        .filter(|word| word.chars().count() == target_word.chars().count())
+        .collect();
+
+    let target_length = target_word.chars().count() as i32;
+    let mut game = GameManager::new(max_steps, target_length, target_length, None);
+    game.init_word(target_word).await;
+
+    let mut tried: Vec<char> = Vec::new();
+    let mut candidates_exhausted = false;
+
+    loop {
+        if game.check_win().await || game.check_lose().await {
+            break;
+        }
+
+        if candidates.is_empty() {
+            candidates_exhausted = true;
+            break;
+        }
+
+        let Some(letter) = next_guess(&candidates, &tried) else {
+            break;
+        };
+        tried.push(letter);
+
+        let found = game.check_letter(letter).await;
+        if !found {
+            game.record_miss(letter);
+        }
+
+        let pattern = game.revealed_pattern();
+        let absent = game.incorrect_letters();
+        candidates.retain(|word| matches_pattern(word, &pattern, absent));
+
+        if verbose {
+            println!(
+                "Guessed '{}' -> {} ({} candidates remain)",
+                letter,
+                if found { "hit" } else { "miss" },
+                candidates.len()
+            );
+        }
+    }
+
+    let won = game.check_win().await;
+    Ok(SolverOutcome { won, wrong_guesses: max_steps - game.steps_left(), steps_left: game.steps_left(), candidates_exhausted })
+}
+
+/// Picks the untried letter that appears in the most remaining candidates,
+/// counting each letter at most once per word so common-but-repeated letters
+/// don't dominate the tally. Ties go to the letter more common in English.
+fn next_guess(candidates: &[String], tried: &[char]) -> Option<char> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for word in candidates {
+        let letters: HashSet<char> = word.chars().flat_map(|letter| letter.to_lowercase()).collect();
+        for letter in letters {
+            *counts.entry(letter).or_insert(0) += 1;
+        }
+    }
+
+    let mut best: Option<(char, usize)> = None;
+    for &letter in ENGLISH_LETTER_FREQUENCY.iter() {
+        if tried.contains(&letter) {
+            continue;
+        }
+        let count = counts.get(&letter).copied().unwrap_or(0);
+        if best.is_none_or(|(_, best_count)| count > best_count) {
+            best = Some((letter, count));
+        }
+    }
+    best.map(|(letter, _)| letter)
+}
+
+/// Whether `word` is still consistent with the revealed pattern and contains
+/// none of the letters already confirmed absent.
+fn matches_pattern(word: &str, pattern: &[Option<char>], absent_letters: &[char]) -> bool {
+    let letters: Vec<char> = word.to_lowercase().chars().collect();
+    if letters.len() != pattern.len() {
+        return false;
+    }
+
+    for (letter, expected) in letters.iter().zip(pattern.iter()) {
+        if let Some(expected_letter) = expected {
+            if letter != expected_letter {
+                return false;
+            }
+        }
+    }
+
+    !letters.iter().any(|letter| absent_letters.contains(letter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_guess_picks_the_letter_touching_most_candidates() {
+        let candidates = vec!["bat".to_string(), "cat".to_string(), "hat".to_string()];
+        assert_eq!(next_guess(&candidates, &[]), Some('t'));
+    }
+
+    #[test]
+    fn next_guess_breaks_ties_by_english_frequency() {
+        let candidates = vec!["sea".to_string(), "tea".to_string()];
+        assert_eq!(next_guess(&candidates, &[]), Some('e'));
+    }
+
+    #[test]
+    fn next_guess_skips_already_tried_letters() {
+        let candidates = vec!["bat".to_string(), "cat".to_string(), "hat".to_string()];
+        assert_eq!(next_guess(&candidates, &['a', 't']), Some('h'));
+    }
+
+    #[test]
+    fn next_guess_returns_none_once_every_letter_is_tried() {
+        let tried: Vec<char> = ENGLISH_LETTER_FREQUENCY.to_vec();
+        assert_eq!(next_guess(&["cat".to_string()], &tried), None);
+    }
+
+    #[test]
+    fn matches_pattern_rejects_wrong_length() {
+        assert!(!matches_pattern("hi", &[None, None, None], &[]));
+    }
+
+    #[test]
+    fn matches_pattern_requires_revealed_positions_to_match() {
+        let pattern = vec![Some('c'), Some('a'), Some('t')];
+        assert!(matches_pattern("cat", &pattern, &[]));
+        assert!(!matches_pattern("cot", &pattern, &[]));
+    }
+
+    #[test]
+    fn matches_pattern_excludes_words_with_absent_letters() {
+        let pattern = vec![None, None, None];
+        assert!(!matches_pattern("cat", &pattern, &['a']));
+        assert!(matches_pattern("dog", &pattern, &['a']));
+    }
+}